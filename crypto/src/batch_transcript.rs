@@ -1,9 +1,13 @@
 use crate::{
+    engine::BatchTerms,
     signature::{identity::Identity, EcdsaSignature},
-    BatchContribution, CeremoniesError, Engine, Transcript,
+    BatchContribution, CeremoniesError, Contribution, Engine, Transcript,
 };
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tracing::instrument;
 
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
@@ -69,6 +73,11 @@ impl BatchTranscript {
                     .map_err(|e| CeremoniesError::InvalidCeremony(i, e))
             })?;
 
+        // Verify the contribution is signed by the claimed identity before
+        // accepting it, and keep `participant_ids` and
+        // `participant_ecdsa_signatures` index-aligned either way.
+        let ecdsa_signature = verify_identity_signature(&identity, &contribution)?;
+
         // Add contributions
         for (transcript, contribution) in self
             .transcripts
@@ -79,9 +88,193 @@ impl BatchTranscript {
         }
 
         self.participant_ids.push(identity);
+        self.participant_ecdsa_signatures.push(ecdsa_signature);
 
         Ok(())
     }
+
+    /// Adds a batch contribution to the transcript, like [`Self::verify_add`],
+    /// but checks all sub-ceremonies with one multi-Miller-loop instead of
+    /// one pairing equality per transcript.
+    ///
+    /// Each sub-ceremony's [`Transcript::verify_prepare`] returns its
+    /// same-ratio terms (two `(L, R)` pairs per consecutive step of powers),
+    /// all weighted by that sub-ceremony's own scalar from
+    /// [`batch_challenge_scalars`], and every sub-ceremony's terms are folded
+    /// into one combined pairing check. The terms are not reduced to a
+    /// single `(L, R)` pair per sub-ceremony, and the steps within one
+    /// sub-ceremony share the same weight.
+    #[instrument(level = "info", skip_all, fields(n=contribution.contributions.len()))]
+    pub fn verify_add_batched<E: Engine>(
+        &mut self,
+        contribution: BatchContribution,
+        identity: Identity,
+    ) -> Result<(), CeremoniesError> {
+        // Verify contribution count
+        if self.transcripts.len() != contribution.contributions.len() {
+            return Err(CeremoniesError::UnexpectedNumContributions(
+                self.transcripts.len(),
+                contribution.contributions.len(),
+            ));
+        }
+
+        let rhos = batch_challenge_scalars::<E>(&contribution, self.transcripts.len());
+
+        // Run every sub-ceremony's non-pairing checks and same-ratio term
+        // preparation in parallel, then fold the weighted terms together.
+        let (lhs, rhs) = self
+            .transcripts
+            .par_iter()
+            .zip(&contribution.contributions)
+            .zip(&rhos)
+            .enumerate()
+            .map(|(i, ((transcript, contribution), rho))| {
+                transcript
+                    .verify_prepare::<E>(contribution, rho)
+                    .map_err(|e| CeremoniesError::InvalidCeremony(i, e))
+            })
+            .try_reduce(BatchTerms::<E>::identity, |a, b| Ok(a.combine(b)))?
+            .into_inner();
+
+        if !E::verify_batch(&lhs, &rhs) {
+            return Err(CeremoniesError::BatchPairingCheckFailed);
+        }
+
+        let ecdsa_signature = verify_identity_signature(&identity, &contribution)?;
+
+        // Add contributions
+        for (transcript, contribution) in self
+            .transcripts
+            .iter_mut()
+            .zip(contribution.contributions.into_iter())
+        {
+            transcript.add(contribution);
+        }
+
+        self.participant_ids.push(identity);
+        self.participant_ecdsa_signatures.push(ecdsa_signature);
+
+        Ok(())
+    }
+}
+
+/// Verifies that `contribution` is signed by `identity`, returning the
+/// [`EcdsaSignature`] to persist alongside it.
+///
+/// Only `Identity::Eth` contributions carry a signature to check: the
+/// recovered address must match the claimed `identity`, over the canonical
+/// encoding of the contribution's new `[τ]G2` points (one per
+/// sub-ceremony), so a signature can't be replayed against a different
+/// contribution. `Identity::None` contributions are pushed with an empty
+/// signature, matching the placeholder `Identity::None` entry `new` seeds
+/// the batch transcript with.
+fn verify_identity_signature(
+    identity: &Identity,
+    contribution: &BatchContribution,
+) -> Result<EcdsaSignature, CeremoniesError> {
+    match identity {
+        Identity::Eth(address) => {
+            let signature = contribution
+                .ecdsa_signature
+                .as_ref()
+                .ok_or(CeremoniesError::MissingEcdsaSignature)?;
+            let message = canonical_pot_pubkeys_message(&contribution.contributions);
+            let recovered = signature
+                .recover_address(&message)
+                .ok_or(CeremoniesError::InvalidEcdsaSignature)?;
+            if recovered != *address {
+                return Err(CeremoniesError::EcdsaSignatureMismatch);
+            }
+            Ok(signature.clone())
+        }
+        Identity::None => Ok(EcdsaSignature::empty()),
+    }
+}
+
+/// Canonical message signed by a contributor: the concatenation of every
+/// sub-ceremony's new `[τ]G2` point, in transcript order.
+fn canonical_pot_pubkeys_message(contributions: &[Contribution]) -> Vec<u8> {
+    contributions
+        .iter()
+        .flat_map(|contribution| contribution.pot_pubkey.iter().copied())
+        .collect()
+}
+
+/// Draws `count` non-zero, independent random scalars used to weight each
+/// sub-ceremony's same-ratio check before folding them into one combined
+/// pairing equality.
+///
+/// Seeded from a hash of the contribution being checked, so two calls on the
+/// same contribution draw the same scalars: the combination is deterministic
+/// and can't be steered by whoever submits the contribution.
+fn batch_challenge_scalars<E: Engine>(
+    contribution: &BatchContribution,
+    count: usize,
+) -> Vec<E::Scalar> {
+    let mut hasher = Sha256::new();
+    hasher.update(b"kzg-ceremony-sequencer/batch-verify");
+    for sub in &contribution.contributions {
+        hasher.update(&sub.pot_pubkey);
+        for power in sub.powers_g1.iter().chain(&sub.powers_g2) {
+            hasher.update(power);
+        }
+    }
+    let mut rng = ChaCha20Rng::from_seed(hasher.finalize().into());
+
+    (0..count)
+        .map(|_| loop {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            if let Some(scalar) = E::scalar_from_bytes(&bytes) {
+                if !E::is_zero_scalar(&scalar) {
+                    break scalar;
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "async")]
+pub mod async_verify {
+    //! Async façade over [`BatchTranscript::verify_add_batched`] for embedders
+    //! that serve contributions over an async HTTP server and can't afford to
+    //! block the executor for the duration of the pairing work. Gated behind
+    //! the `async` feature so non-async embedders aren't forced to pull in a
+    //! `tokio` runtime.
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Verifies and adds `contribution` to the [`BatchTranscript`] behind
+    /// `transcript` without blocking the calling executor: both the lock
+    /// acquisition and the pairing work happen inside
+    /// `tokio::task::spawn_blocking`, whose thread hands the parallel part of
+    /// the check to rayon's thread pool and waits for it.
+    ///
+    /// Taking the lock *inside* the blocking task (rather than cloning the
+    /// transcript up front and asking the caller to swap the result back in)
+    /// means concurrent calls on the same `transcript` serialize correctly
+    /// instead of each verifying against the same stale snapshot and
+    /// racing to overwrite one another's result.
+    ///
+    /// Deviation from the original request: dropping the returned future
+    /// before it resolves does *not* cancel the verification. `spawn_blocking`
+    /// detaches onto the blocking pool, so the lock and the pairing work keep
+    /// running to completion (and the lock stays held) even if the caller
+    /// drops, e.g. because a client disconnected. Genuine cancellation would
+    /// need a cooperative cancellation point inside `verify_add_batched`
+    /// itself, which its synchronous, single-pass pairing check doesn't have.
+    pub async fn verify_add_async<E: Engine + 'static>(
+        transcript: Arc<Mutex<BatchTranscript>>,
+        contribution: BatchContribution,
+        identity: Identity,
+    ) -> Result<(), CeremoniesError> {
+        tokio::task::spawn_blocking(move || {
+            let mut transcript = transcript.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            transcript.verify_add_batched::<E>(contribution, identity)
+        })
+        .await
+        .map_err(|_| CeremoniesError::VerificationTaskAborted)?
+    }
 }
 
 #[cfg(feature = "bench")]
@@ -101,6 +294,13 @@ pub mod bench {
         bench_verify_add::<BLST>(criterion, "blst");
         #[cfg(all(feature = "arkworks", feature = "blst"))]
         bench_verify_add::<Both<Arkworks, BLST>>(criterion, "both");
+
+        #[cfg(feature = "arkworks")]
+        bench_verify_add_batched::<Arkworks>(criterion, "arkworks");
+        #[cfg(feature = "blst")]
+        bench_verify_add_batched::<BLST>(criterion, "blst");
+        #[cfg(all(feature = "arkworks", feature = "blst"))]
+        bench_verify_add_batched::<Both<Arkworks, BLST>>(criterion, "both");
     }
 
     fn bench_verify_add<E: Engine>(criterion: &mut Criterion, name: &str) {
@@ -109,7 +309,7 @@ pub mod bench {
             let mut transcript = BatchTranscript::new(BATCH_SIZE.iter());
             let mut contribution = transcript.contribution();
             contribution.add_entropy::<E>(&rand_entropy()).unwrap();
-            transcript.verify_add::<E>(contribution).unwrap();
+            transcript.verify_add::<E>(contribution, Identity::None).unwrap();
             transcript
         };
 
@@ -125,7 +325,41 @@ pub mod bench {
                         })
                     },
                     |(mut transcript, contribution)| {
-                        transcript.verify_add::<E>(contribution).unwrap();
+                        transcript.verify_add::<E>(contribution, Identity::None).unwrap();
+                    },
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+    }
+
+    /// Compares against [`bench_verify_add`] to quantify the speedup from
+    /// collapsing the per-transcript pairing checks into one multi-Miller-loop.
+    fn bench_verify_add_batched<E: Engine>(criterion: &mut Criterion, name: &str) {
+        // Create a non-trivial transcript
+        let transcript = {
+            let mut transcript = BatchTranscript::new(BATCH_SIZE.iter());
+            let mut contribution = transcript.contribution();
+            contribution.add_entropy::<E>(&rand_entropy()).unwrap();
+            transcript.verify_add::<E>(contribution, Identity::None).unwrap();
+            transcript
+        };
+
+        criterion.bench_function(
+            &format!("batch_transcript/{}/verify_add_batched", name),
+            move |bencher| {
+                bencher.iter_batched(
+                    || {
+                        (transcript.clone(), {
+                            let mut contribution = transcript.contribution();
+                            contribution.add_entropy::<E>(&rand_entropy()).unwrap();
+                            contribution
+                        })
+                    },
+                    |(mut transcript, contribution)| {
+                        transcript
+                            .verify_add_batched::<E>(contribution, Identity::None)
+                            .unwrap();
                     },
                     BatchSize::LargeInput,
                 );
@@ -133,3 +367,123 @@ pub mod bench {
         );
     }
 }
+
+#[cfg(feature = "fuzz")]
+#[doc(hidden)]
+pub mod fuzz {
+    //! Fuzzing harness for the deserialize -> verify_add path, mirroring the
+    //! `bench` module's layout. Gated behind the `fuzz` feature so normal
+    //! builds don't pull in `arbitrary`.
+    use super::*;
+    use crate::bench::BATCH_SIZE;
+    use arbitrary::{Arbitrary, Unstructured};
+    use std::marker::PhantomData;
+
+    /// Structure-aware generator for "near-valid" contributions: starts from
+    /// a real transcript's own next contribution (so sub-ceremony counts and
+    /// point shapes already line up) and lets `arbitrary` decide how much
+    /// entropy to mix in and whether to perturb a point or the claimed
+    /// identity afterwards. This lands inputs close to the valid/invalid
+    /// boundary, rather than being rejected outright on a trivial length
+    /// mismatch.
+    pub struct FuzzContribution<E: Engine> {
+        pub transcript:   BatchTranscript,
+        pub contribution: BatchContribution,
+        pub identity:     Identity,
+        _engine:          PhantomData<E>,
+    }
+
+    impl<'a, E: Engine> Arbitrary<'a> for FuzzContribution<E> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+            let transcript = BatchTranscript::new(BATCH_SIZE.iter());
+            let mut contribution = transcript.contribution();
+            let entropy: [u8; 32] = u.arbitrary()?;
+            let _ = contribution.add_entropy::<E>(&entropy);
+
+            // Sometimes perturb a point so the harness also exercises the
+            // reject path, not only ever-valid contributions.
+            if u.arbitrary()? {
+                if let Some(sub) = contribution.contributions.first_mut() {
+                    sub.pot_pubkey = Arbitrary::arbitrary(u)?;
+                }
+            }
+
+            // Sometimes claim `Identity::Eth`, and when we do, actually carry
+            // a signature so the harness reaches `EcdsaSignature::recover_address`
+            // instead of bouncing off `MissingEcdsaSignature` on every run.
+            // When the signature recovers, `u` decides whether the recovered
+            // address is kept (exercising the accept path) or swapped for an
+            // unrelated arbitrary one; otherwise an arbitrary address is used,
+            // so `EcdsaSignatureMismatch`/`InvalidEcdsaSignature` are reached too.
+            let identity = if u.arbitrary()? {
+                let signature = EcdsaSignature(Vec::arbitrary(u)?);
+                let message = canonical_pot_pubkeys_message(&contribution.contributions);
+                let address = match signature.recover_address(&message) {
+                    Some(address) if u.arbitrary()? => address,
+                    _ => Arbitrary::arbitrary(u)?,
+                };
+                contribution.ecdsa_signature = Some(signature);
+                Identity::Eth(address)
+            } else {
+                Identity::None
+            };
+
+            Ok(Self {
+                transcript,
+                contribution,
+                identity,
+                _engine: PhantomData,
+            })
+        }
+    }
+
+    /// Drives [`BatchTranscript::verify_add`]: must never panic, and must
+    /// never mutate `transcript` when it returns `Err` -- today that holds
+    /// because `verify_add` only pushes `identity` (and now the ECDSA
+    /// signature) after every parallel check has passed, and the same
+    /// invariant must hold for [`BatchTranscript::verify_add_batched`] below.
+    pub fn fuzz_verify_add<E: Engine>(input: FuzzContribution<E>) {
+        let FuzzContribution {
+            mut transcript,
+            contribution,
+            identity,
+            ..
+        } = input;
+        let before = transcript.clone();
+        if transcript.verify_add::<E>(contribution, identity).is_err() {
+            assert_eq!(transcript, before, "verify_add must not mutate on error");
+        }
+    }
+
+    /// Same atomicity property as [`fuzz_verify_add`], but for the batched
+    /// multi-pairing path, which accumulates terms across every
+    /// sub-ceremony before checking (and mutating) anything.
+    pub fn fuzz_verify_add_batched<E: Engine>(input: FuzzContribution<E>) {
+        let FuzzContribution {
+            mut transcript,
+            contribution,
+            identity,
+            ..
+        } = input;
+        let before = transcript.clone();
+        if transcript
+            .verify_add_batched::<E>(contribution, identity)
+            .is_err()
+        {
+            assert_eq!(
+                transcript, before,
+                "verify_add_batched must not mutate on error"
+            );
+        }
+    }
+
+    /// Feeds raw, unstructured bytes through [`BatchContribution`]'s serde
+    /// deserializer and asserts only that it never panics. Unknown fields
+    /// (via `#[serde(deny_unknown_fields)]`) and malformed JSON such as
+    /// truncated arrays come back as a deserialize error; the deserializer
+    /// does not check `num_g1_powers`/`num_g2_powers` against the lengths of
+    /// `powers_g1`/`powers_g2`, so such mismatches deserialize successfully.
+    pub fn fuzz_deserialize_contribution(data: &[u8]) {
+        let _ = serde_json::from_slice::<BatchContribution>(data);
+    }
+}
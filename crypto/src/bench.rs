@@ -0,0 +1,12 @@
+use rand::RngCore;
+
+/// `(num_g1_powers, num_g2_powers)` per sub-ceremony used to build a
+/// representative [`crate::BatchTranscript`] for benchmarking.
+pub const BATCH_SIZE: [(usize, usize); 2] = [(4096, 65), (4096, 65)];
+
+#[must_use]
+pub fn rand_entropy() -> [u8; 32] {
+    let mut entropy = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut entropy);
+    entropy
+}
@@ -0,0 +1,21 @@
+use crate::{engine::Engine, error::CeremonyError, signature::EcdsaSignature, transcript::Contribution};
+use serde::{Deserialize, Serialize};
+
+/// A contribution to every sub-ceremony in a [`crate::BatchTranscript`] at
+/// once, submitted by a single participant.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchContribution {
+    pub contributions:   Vec<Contribution>,
+    pub ecdsa_signature: Option<EcdsaSignature>,
+}
+
+impl BatchContribution {
+    /// Mixes `entropy` into every sub-ceremony's contribution in place.
+    pub fn add_entropy<E: Engine>(&mut self, entropy: &[u8; 32]) -> Result<(), CeremonyError> {
+        for contribution in &mut self.contributions {
+            contribution.add_entropy::<E>(entropy)?;
+        }
+        Ok(())
+    }
+}
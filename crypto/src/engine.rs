@@ -0,0 +1,250 @@
+use crate::error::CeremonyError;
+
+/// Abstracts over the pairing-curve backend (`arkworks`, `blst`, or both run
+/// side by side and cross-checked) so the rest of the crate never touches
+/// curve arithmetic directly.
+pub trait Engine: 'static {
+    type G1: Copy + Send + Sync;
+    type G2: Copy + Send + Sync;
+    type Scalar: Copy + Send + Sync;
+
+    fn decode_g1(bytes: &[u8], index: usize) -> Result<Self::G1, CeremonyError>;
+    fn decode_g2(bytes: &[u8], index: usize) -> Result<Self::G2, CeremonyError>;
+    fn encode_g1(point: Self::G1) -> Vec<u8>;
+    fn encode_g2(point: Self::G2) -> Vec<u8>;
+
+    fn scalar_from_bytes(bytes: &[u8; 32]) -> Option<Self::Scalar>;
+    fn is_zero_scalar(scalar: &Self::Scalar) -> bool;
+    fn one_scalar() -> Self::Scalar;
+    fn mul_scalar(a: Self::Scalar, b: Self::Scalar) -> Self::Scalar;
+
+    fn neg_g1(point: Self::G1) -> Self::G1;
+    fn mul_g1(point: Self::G1, scalar: Self::Scalar) -> Self::G1;
+    fn mul_g2(point: Self::G2, scalar: Self::Scalar) -> Self::G2;
+
+    /// The fixed `G2` generator, used as a ratio anchor independent of any
+    /// particular transcript's state.
+    fn g2_generator() -> Self::G2;
+
+    /// Checks `e(lhs[0], rhs[0]) * .. * e(lhs[n-1], rhs[n-1]) == 1` with one
+    /// multi-Miller-loop, rather than `n` separate pairing checks.
+    fn verify_batch(lhs: &[Self::G1], rhs: &[Self::G2]) -> bool;
+}
+
+/// Accumulates the `(L, R)` pairing terms contributed by each sub-ceremony
+/// in a batch, so they can be folded into one [`Engine::verify_batch`] call
+/// instead of one pairing check per sub-ceremony. See
+/// [`crate::BatchTranscript::verify_add_batched`].
+pub struct BatchTerms<E: Engine> {
+    lhs: Vec<E::G1>,
+    rhs: Vec<E::G2>,
+}
+
+impl<E: Engine> BatchTerms<E> {
+    #[must_use]
+    pub fn new(lhs: E::G1, rhs: E::G2) -> Self {
+        Self {
+            lhs: vec![lhs],
+            rhs: vec![rhs],
+        }
+    }
+
+    #[must_use]
+    pub fn identity() -> Self {
+        Self {
+            lhs: Vec::new(),
+            rhs: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn combine(mut self, mut other: Self) -> Self {
+        self.lhs.append(&mut other.lhs);
+        self.rhs.append(&mut other.rhs);
+        self
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> (Vec<E::G1>, Vec<E::G2>) {
+        (self.lhs, self.rhs)
+    }
+}
+
+/// Folds a toy `G2` element down to a `G1`-sized digest by wrapping-adding
+/// its two halves together.
+fn fold_g2_to_g1(point: &[u8; 96]) -> [u8; 48] {
+    let (lo, hi) = point.split_at(48);
+    let mut folded = [0u8; 48];
+    for i in 0..48 {
+        folded[i] = lo[i].wrapping_add(hi[i]);
+    }
+    folded
+}
+
+macro_rules! impl_toy_engine {
+    ($name:ident) => {
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name;
+
+        impl Engine for $name {
+            type G1 = [u8; 48];
+            type G2 = [u8; 96];
+            type Scalar = [u8; 32];
+
+            fn decode_g1(bytes: &[u8], index: usize) -> Result<Self::G1, CeremonyError> {
+                bytes
+                    .try_into()
+                    .map_err(|_| CeremonyError::InvalidG1Power(index))
+            }
+
+            fn decode_g2(bytes: &[u8], index: usize) -> Result<Self::G2, CeremonyError> {
+                bytes
+                    .try_into()
+                    .map_err(|_| CeremonyError::InvalidG2Power(index))
+            }
+
+            fn encode_g1(point: Self::G1) -> Vec<u8> {
+                point.to_vec()
+            }
+
+            fn encode_g2(point: Self::G2) -> Vec<u8> {
+                point.to_vec()
+            }
+
+            fn scalar_from_bytes(bytes: &[u8; 32]) -> Option<Self::Scalar> {
+                Some(*bytes)
+            }
+
+            fn is_zero_scalar(scalar: &Self::Scalar) -> bool {
+                scalar.iter().all(|byte| *byte == 0)
+            }
+
+            fn one_scalar() -> Self::Scalar {
+                [1; 32]
+            }
+
+            fn mul_scalar(mut a: Self::Scalar, b: Self::Scalar) -> Self::Scalar {
+                a.iter_mut()
+                    .zip(b.iter())
+                    .for_each(|(x, y)| *x = x.wrapping_mul(*y));
+                a
+            }
+
+            fn neg_g1(mut point: Self::G1) -> Self::G1 {
+                point.iter_mut().for_each(|byte| *byte = byte.wrapping_neg());
+                point
+            }
+
+            fn mul_g1(mut point: Self::G1, scalar: Self::Scalar) -> Self::G1 {
+                point
+                    .iter_mut()
+                    .zip(scalar.iter().cycle())
+                    .for_each(|(byte, s)| *byte = byte.wrapping_mul(*s));
+                point
+            }
+
+            fn mul_g2(mut point: Self::G2, scalar: Self::Scalar) -> Self::G2 {
+                point
+                    .iter_mut()
+                    .zip(scalar.iter().cycle())
+                    .for_each(|(byte, s)| *byte = byte.wrapping_mul(*s));
+                point
+            }
+
+            fn g2_generator() -> Self::G2 {
+                [1; 96]
+            }
+
+            /// Folds every `(l, r)` pair down to a toy pairing value --
+            /// `l` elementwise-multiplied with `r` folded down to `l`'s size
+            /// -- and wrapping-sums them, mirroring how [`Self::mul_g1`] and
+            /// [`Self::neg_g1`] are themselves elementwise-multiplicative:
+            /// `pairing(mul_g1(p, s), q) == s * pairing(p, q)` and
+            /// `pairing(neg_g1(p), q) == -pairing(p, q)` hold exactly, so a
+            /// sum of these toy pairing values is zero iff the batched
+            /// same-ratio checks it was built from actually hold.
+            fn verify_batch(lhs: &[Self::G1], rhs: &[Self::G2]) -> bool {
+                if lhs.len() != rhs.len() {
+                    return false;
+                }
+                let mut acc = [0u8; 48];
+                for (l, r) in lhs.iter().zip(rhs) {
+                    let folded_r = fold_g2_to_g1(r);
+                    for i in 0..48 {
+                        acc[i] = acc[i].wrapping_add(l[i].wrapping_mul(folded_r[i]));
+                    }
+                }
+                acc == [0u8; 48]
+            }
+        }
+    };
+}
+
+impl_toy_engine!(Arkworks);
+impl_toy_engine!(BLST);
+
+/// Runs every pairing check against both backends and only accepts the
+/// result if they agree, so a bug in either implementation can't silently
+/// pass verification on its own.
+#[derive(Clone, Copy, Debug)]
+pub struct Both<A, B>(std::marker::PhantomData<(A, B)>);
+
+impl<A: Engine, B: Engine<Scalar = A::Scalar>> Engine for Both<A, B> {
+    type G1 = (A::G1, B::G1);
+    type G2 = (A::G2, B::G2);
+    type Scalar = A::Scalar;
+
+    fn decode_g1(bytes: &[u8], index: usize) -> Result<Self::G1, CeremonyError> {
+        Ok((A::decode_g1(bytes, index)?, B::decode_g1(bytes, index)?))
+    }
+
+    fn decode_g2(bytes: &[u8], index: usize) -> Result<Self::G2, CeremonyError> {
+        Ok((A::decode_g2(bytes, index)?, B::decode_g2(bytes, index)?))
+    }
+
+    fn encode_g1(point: Self::G1) -> Vec<u8> {
+        A::encode_g1(point.0)
+    }
+
+    fn encode_g2(point: Self::G2) -> Vec<u8> {
+        A::encode_g2(point.0)
+    }
+
+    fn scalar_from_bytes(bytes: &[u8; 32]) -> Option<Self::Scalar> {
+        A::scalar_from_bytes(bytes)
+    }
+
+    fn is_zero_scalar(scalar: &Self::Scalar) -> bool {
+        A::is_zero_scalar(scalar)
+    }
+
+    fn one_scalar() -> Self::Scalar {
+        A::one_scalar()
+    }
+
+    fn mul_scalar(a: Self::Scalar, b: Self::Scalar) -> Self::Scalar {
+        A::mul_scalar(a, b)
+    }
+
+    fn neg_g1(point: Self::G1) -> Self::G1 {
+        (A::neg_g1(point.0), B::neg_g1(point.1))
+    }
+
+    fn mul_g1(point: Self::G1, scalar: Self::Scalar) -> Self::G1 {
+        (A::mul_g1(point.0, scalar), B::mul_g1(point.1, scalar))
+    }
+
+    fn mul_g2(point: Self::G2, scalar: Self::Scalar) -> Self::G2 {
+        (A::mul_g2(point.0, scalar), B::mul_g2(point.1, scalar))
+    }
+
+    fn g2_generator() -> Self::G2 {
+        (A::g2_generator(), B::g2_generator())
+    }
+
+    fn verify_batch(lhs: &[Self::G1], rhs: &[Self::G2]) -> bool {
+        let (lhs_a, lhs_b): (Vec<_>, Vec<_>) = lhs.iter().copied().unzip();
+        let (rhs_a, rhs_b): (Vec<_>, Vec<_>) = rhs.iter().copied().unzip();
+        A::verify_batch(&lhs_a, &rhs_a) && B::verify_batch(&lhs_b, &rhs_b)
+    }
+}
@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+/// Errors from checking that a single sub-ceremony's contribution validly
+/// extends its [`crate::Transcript`].
+#[derive(Error, Clone, PartialEq, Eq, Debug)]
+pub enum CeremonyError {
+    #[error("expected {0} G1 powers, got {1}")]
+    UnexpectedNumG1Powers(usize, usize),
+    #[error("expected {0} G2 powers, got {1}")]
+    UnexpectedNumG2Powers(usize, usize),
+    #[error("G1 power at index {0} is not a valid curve point")]
+    InvalidG1Power(usize),
+    #[error("G2 power at index {0} is not a valid curve point")]
+    InvalidG2Power(usize),
+    #[error("contribution is not a valid extension of the transcript")]
+    InvalidWitness,
+}
+
+/// Errors from checking a whole [`crate::BatchContribution`] against a
+/// [`crate::BatchTranscript`].
+#[derive(Error, Clone, PartialEq, Eq, Debug)]
+pub enum CeremoniesError {
+    #[error("expected {0} contributions, got {1}")]
+    UnexpectedNumContributions(usize, usize),
+    #[error("ceremony {0} is invalid: {1}")]
+    InvalidCeremony(usize, CeremonyError),
+    #[error("combined batch pairing check failed")]
+    BatchPairingCheckFailed,
+    #[error("identity claims a signature but the contribution did not include one")]
+    MissingEcdsaSignature,
+    #[error("ecdsa signature does not recover to a valid address")]
+    InvalidEcdsaSignature,
+    #[error("ecdsa signature does not match the claimed identity")]
+    EcdsaSignatureMismatch,
+    #[error("verification task panicked or was cancelled")]
+    VerificationTaskAborted,
+}
@@ -0,0 +1,16 @@
+pub mod batch_transcript;
+pub mod contribution;
+pub mod engine;
+pub mod error;
+pub mod signature;
+pub mod transcript;
+
+#[cfg(any(feature = "bench", feature = "fuzz"))]
+#[doc(hidden)]
+pub mod bench;
+
+pub use batch_transcript::BatchTranscript;
+pub use contribution::BatchContribution;
+pub use engine::{Arkworks, Both, Engine, BLST};
+pub use error::{CeremoniesError, CeremonyError};
+pub use transcript::{Contribution, Transcript};
@@ -0,0 +1,103 @@
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+/// A 20-byte Ethereum address.
+pub type Address = [u8; 20];
+
+/// The identity a participant claims when submitting a contribution.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum Identity {
+    None,
+    Eth(Address),
+}
+
+/// Recovers the Ethereum address behind a 65-byte `(r, s, v)` ECDSA
+/// signature over `message`, or `None` if `signature` isn't well-formed or
+/// doesn't recover to a valid public key.
+///
+/// `message` is hashed with Keccak-256 directly (no `personal_sign` prefix):
+/// this binds a signature to one contribution, it isn't meant to be a
+/// wallet-facing signing request.
+pub(crate) fn recover_eth_address(signature: &[u8], message: &[u8]) -> Option<Address> {
+    let [rs @ .., v] = signature else {
+        return None;
+    };
+    let signature = Signature::try_from(rs).ok()?;
+    let recovery_id = RecoveryId::try_from(normalize_recovery_byte(*v)).ok()?;
+    let verifying_key =
+        VerifyingKey::recover_from_digest(Keccak256::new_with_prefix(message), &signature, recovery_id)
+            .ok()?;
+
+    // The Ethereum address is the low 20 bytes of the Keccak-256 hash of the
+    // uncompressed public key, excluding its 0x04 tag byte.
+    let encoded_point = verifying_key.to_sec1_point(false);
+    let digest = Keccak256::digest(&encoded_point.as_bytes()[1..]);
+    let mut address = Address::default();
+    address.copy_from_slice(&digest[12..]);
+    Some(address)
+}
+
+/// Accepts both the raw `{0, 1}` recovery id and Ethereum's legacy
+/// `{27, 28}` `v` encoding.
+fn normalize_recovery_byte(v: u8) -> u8 {
+    match v {
+        27 | 28 => v - 27,
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::SigningKey;
+
+    fn test_signing_key() -> SigningKey {
+        SigningKey::from_slice(&[7u8; 32]).unwrap()
+    }
+
+    fn sign(signing_key: &SigningKey, message: &[u8]) -> Vec<u8> {
+        let (signature, recovery_id) =
+            signing_key.sign_digest_recoverable(Keccak256::new_with_prefix(message));
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte());
+        bytes
+    }
+
+    fn address_of(signing_key: &SigningKey) -> Address {
+        let encoded_point = signing_key.verifying_key().to_sec1_point(false);
+        let digest = Keccak256::digest(&encoded_point.as_bytes()[1..]);
+        let mut address = Address::default();
+        address.copy_from_slice(&digest[12..]);
+        address
+    }
+
+    #[test]
+    fn recovers_the_signer_address() {
+        let signing_key = test_signing_key();
+        let message = b"sub-ceremony pot_pubkey bytes";
+        let signature = sign(&signing_key, message);
+
+        assert_eq!(
+            recover_eth_address(&signature, message),
+            Some(address_of(&signing_key))
+        );
+    }
+
+    #[test]
+    fn rejects_a_signature_over_a_different_message() {
+        let signing_key = test_signing_key();
+        let signature = sign(&signing_key, b"original message");
+
+        assert_ne!(
+            recover_eth_address(&signature, b"tampered message"),
+            Some(address_of(&signing_key))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_signatures() {
+        assert_eq!(recover_eth_address(&[0u8; 10], b"message"), None);
+    }
+}
@@ -0,0 +1,23 @@
+pub mod identity;
+
+use identity::Address;
+use serde::{Deserialize, Serialize};
+
+/// An ECDSA (secp256k1) signature over a contribution, binding it to the
+/// participant's claimed [`identity::Identity`].
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EcdsaSignature(pub Vec<u8>);
+
+impl EcdsaSignature {
+    #[must_use]
+    pub fn empty() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Recovers the Ethereum address that produced this signature over
+    /// `message`, or `None` if the signature is malformed.
+    pub fn recover_address(&self, message: &[u8]) -> Option<Address> {
+        identity::recover_eth_address(&self.0, message)
+    }
+}
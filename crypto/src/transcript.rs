@@ -0,0 +1,257 @@
+use crate::{
+    engine::{BatchTerms, Engine},
+    error::CeremonyError,
+};
+use serde::{Deserialize, Serialize};
+
+/// The running state of a single powers-of-tau sub-ceremony: every power
+/// contributed so far, plus the current `[τ]G2` (`pot_pubkey`) that the next
+/// contribution must extend.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Transcript {
+    pub num_g1_powers: usize,
+    pub num_g2_powers: usize,
+    pub powers_g1:     Vec<Vec<u8>>,
+    pub powers_g2:     Vec<Vec<u8>>,
+    pub pot_pubkey:    Vec<u8>,
+}
+
+/// One participant's contribution to a single sub-ceremony.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Contribution {
+    pub num_g1_powers: usize,
+    pub num_g2_powers: usize,
+    pub powers_g1:     Vec<Vec<u8>>,
+    pub powers_g2:     Vec<Vec<u8>>,
+    pub pot_pubkey:    Vec<u8>,
+}
+
+const G1_GENERATOR: [u8; 48] = [1; 48];
+const G2_GENERATOR: [u8; 96] = [1; 96];
+
+impl Transcript {
+    #[must_use]
+    pub fn new(num_g1_powers: usize, num_g2_powers: usize) -> Self {
+        Self {
+            num_g1_powers,
+            num_g2_powers,
+            powers_g1: vec![G1_GENERATOR.to_vec(); num_g1_powers],
+            powers_g2: vec![G2_GENERATOR.to_vec(); num_g2_powers],
+            pot_pubkey: G2_GENERATOR.to_vec(),
+        }
+    }
+
+    /// Creates the start of a new contribution to this sub-ceremony.
+    #[must_use]
+    pub fn contribution(&self) -> Contribution {
+        Contribution {
+            num_g1_powers: self.num_g1_powers,
+            num_g2_powers: self.num_g2_powers,
+            powers_g1:     self.powers_g1.clone(),
+            powers_g2:     self.powers_g2.clone(),
+            pot_pubkey:    self.pot_pubkey.clone(),
+        }
+    }
+
+    /// Checks `contribution` against `self`: the power counts must match, its
+    /// `pot_pubkey` must differ from the current one, and its powers must
+    /// form a consistent run with that new `pot_pubkey`. Whether the new
+    /// `pot_pubkey` and powers are derived from `self`'s is not checked.
+    pub fn verify<E: Engine>(&self, contribution: &Contribution) -> Result<(), CeremonyError> {
+        let (lhs, rhs) = self.same_ratio_terms::<E>(contribution)?.into_inner();
+        if E::verify_batch(&lhs, &rhs) {
+            Ok(())
+        } else {
+            Err(CeremonyError::InvalidWitness)
+        }
+    }
+
+    /// Like [`Self::verify`], but instead of pairing-checking immediately,
+    /// weights the same-ratio terms by `rho` and returns them so
+    /// [`crate::BatchTranscript::verify_add_batched`] can fold every
+    /// sub-ceremony's terms into one combined pairing check.
+    pub fn verify_prepare<E: Engine>(
+        &self,
+        contribution: &Contribution,
+        rho: &E::Scalar,
+    ) -> Result<BatchTerms<E>, CeremonyError> {
+        let (lhs, rhs) = self.same_ratio_terms::<E>(contribution)?.into_inner();
+        Ok(lhs
+            .into_iter()
+            .zip(rhs)
+            .fold(BatchTerms::identity(), |acc, (l, r)| {
+                acc.combine(BatchTerms::new(E::mul_g1(l, *rho), r))
+            }))
+    }
+
+    /// Reduces the same-ratio check to a set of `(L, R)` pairs satisfying
+    /// `e(L, R) == 1` once every power has been folded in: two pairs per
+    /// consecutive `(powers_g1[i-1], powers_g1[i])` step (anchored by the
+    /// new `pot_pubkey`) and two pairs per consecutive `(powers_g2[j-1],
+    /// powers_g2[j])` step (anchored by the first g1 step), so every power
+    /// in the run is actually checked against the claimed ratio -- not just
+    /// the first and last.
+    fn same_ratio_terms<E: Engine>(
+        &self,
+        contribution: &Contribution,
+    ) -> Result<BatchTerms<E>, CeremonyError> {
+        if contribution.num_g1_powers != self.num_g1_powers {
+            return Err(CeremonyError::UnexpectedNumG1Powers(
+                self.num_g1_powers,
+                contribution.num_g1_powers,
+            ));
+        }
+        if contribution.num_g2_powers != self.num_g2_powers {
+            return Err(CeremonyError::UnexpectedNumG2Powers(
+                self.num_g2_powers,
+                contribution.num_g2_powers,
+            ));
+        }
+        // A contribution must actually change the pot_pubkey: this is the
+        // only check tying the new contribution back to the previous one,
+        // since the pairing checks below only ever certify a contribution's
+        // *internal* consistency with its own claimed pot_pubkey.
+        if contribution.pot_pubkey == self.pot_pubkey {
+            return Err(CeremonyError::InvalidWitness);
+        }
+
+        let tau_g2 = E::decode_g2(&contribution.pot_pubkey, self.num_g2_powers)?;
+
+        let powers_g1 = contribution
+            .powers_g1
+            .iter()
+            .enumerate()
+            .map(|(i, bytes)| E::decode_g1(bytes, i))
+            .collect::<Result<Vec<_>, _>>()?;
+        let powers_g2 = contribution
+            .powers_g2
+            .iter()
+            .enumerate()
+            .map(|(j, bytes)| E::decode_g2(bytes, j))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let generator_g1 = *powers_g1.first().ok_or(CeremonyError::InvalidG1Power(0))?;
+
+        let mut terms = BatchTerms::identity();
+
+        // Every consecutive pair of g1 powers must share the same ratio:
+        // the contribution's own new pot_pubkey.
+        for window in powers_g1.windows(2) {
+            let [prev, next] = window else {
+                unreachable!("windows(2) always yields 2-element slices")
+            };
+            terms = terms
+                .combine(BatchTerms::new(E::neg_g1(*next), E::g2_generator()))
+                .combine(BatchTerms::new(*prev, tau_g2));
+        }
+
+        // Every consecutive pair of g2 powers must share that same ratio
+        // too, anchored in G1 by the first g1 step (generator_g1, powers_g1[1]).
+        if let Some(next_g1) = powers_g1.get(1) {
+            for window in powers_g2.windows(2) {
+                let [prev, next] = window else {
+                    unreachable!("windows(2) always yields 2-element slices")
+                };
+                terms = terms
+                    .combine(BatchTerms::new(E::neg_g1(*next_g1), *prev))
+                    .combine(BatchTerms::new(generator_g1, *next));
+            }
+        }
+
+        Ok(terms)
+    }
+
+    pub fn add(&mut self, contribution: Contribution) {
+        self.num_g1_powers = contribution.num_g1_powers;
+        self.num_g2_powers = contribution.num_g2_powers;
+        self.powers_g1 = contribution.powers_g1;
+        self.powers_g2 = contribution.powers_g2;
+        self.pot_pubkey = contribution.pot_pubkey;
+    }
+}
+
+impl Contribution {
+    /// Mixes `entropy` into this sub-ceremony's contribution in place:
+    /// raises every power to the next degree of the secret `tau` derived
+    /// from `entropy`, i.e. `powers_g1[i] *= tau^i` and `powers_g2[j] *=
+    /// tau^j`, so the result is still a run of consecutive powers of a
+    /// single (now updated) secret, as [`Transcript::same_ratio_terms`]
+    /// expects.
+    pub fn add_entropy<E: Engine>(&mut self, entropy: &[u8; 32]) -> Result<(), CeremonyError> {
+        let tau = E::scalar_from_bytes(entropy).ok_or(CeremonyError::InvalidWitness)?;
+        if E::is_zero_scalar(&tau) {
+            return Err(CeremonyError::InvalidWitness);
+        }
+
+        let mut tau_power = E::one_scalar();
+        for (i, bytes) in self.powers_g1.iter_mut().enumerate() {
+            let power = E::decode_g1(bytes, i)?;
+            *bytes = E::encode_g1(E::mul_g1(power, tau_power));
+            tau_power = E::mul_scalar(tau_power, tau);
+        }
+
+        let mut tau_power = E::one_scalar();
+        for (j, bytes) in self.powers_g2.iter_mut().enumerate() {
+            let power = E::decode_g2(bytes, j)?;
+            *bytes = E::encode_g2(E::mul_g2(power, tau_power));
+            tau_power = E::mul_scalar(tau_power, tau);
+        }
+
+        let pot_pubkey = E::decode_g2(&self.pot_pubkey, self.num_g2_powers)?;
+        self.pot_pubkey = E::encode_g2(E::mul_g2(pot_pubkey, tau));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Arkworks;
+
+    fn honest_contribution(transcript: &Transcript) -> Contribution {
+        let mut contribution = transcript.contribution();
+        contribution
+            .add_entropy::<Arkworks>(&[42; 32])
+            .expect("entropy is non-zero");
+        contribution
+    }
+
+    #[test]
+    fn accepts_an_honest_contribution() {
+        let transcript = Transcript::new(4, 4);
+        let contribution = honest_contribution(&transcript);
+
+        assert!(transcript.verify::<Arkworks>(&contribution).is_ok());
+    }
+
+    #[test]
+    fn rejects_a_tampered_g1_power() {
+        let transcript = Transcript::new(4, 4);
+        let mut contribution = honest_contribution(&transcript);
+        contribution.powers_g1[2] = vec![0xff; 48];
+
+        assert!(transcript.verify::<Arkworks>(&contribution).is_err());
+    }
+
+    #[test]
+    fn rejects_a_tampered_g2_power() {
+        let transcript = Transcript::new(4, 4);
+        let mut contribution = honest_contribution(&transcript);
+        for power in &mut contribution.powers_g2 {
+            *power = vec![0xff; 96];
+        }
+
+        assert!(transcript.verify::<Arkworks>(&contribution).is_err());
+    }
+
+    #[test]
+    fn rejects_an_unchanged_pot_pubkey() {
+        let transcript = Transcript::new(4, 4);
+        let contribution = transcript.contribution();
+
+        assert!(transcript.verify::<Arkworks>(&contribution).is_err());
+    }
+}